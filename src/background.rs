@@ -0,0 +1,89 @@
+//! Parallax starfield background.
+//!
+//! [`BackgroundConfig`] describes how many background sprites to scatter
+//! across how many depth layers; [`initialise_background`] spawns them,
+//! and `systems::ParallaxSystem` offsets each layer's `Transform` by the
+//! camera position scaled by the layer's depth factor every frame, so
+//! farther layers appear to scroll slower than nearer ones.
+
+use amethyst::core::transform::Transform;
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use amethyst::prelude::*;
+use amethyst::renderer::{Handle, SpriteRender, SpriteSheet};
+use rand::Rng;
+
+/// Parameters for one parallax depth layer.
+#[derive(Debug, Clone, Copy)]
+pub struct ParallaxLayerConfig {
+    /// How far the layer scrolls relative to the camera; 0.0 is fixed to
+    /// the background, 1.0 moves exactly with the camera.
+    pub depth: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub min_size: f32,
+    pub max_size: f32,
+}
+
+/// Resource describing the starfield: how many stars per layer and the
+/// layers themselves. It does not hold the sprite sheet; that is passed to
+/// [`initialise_background`] as a separate argument.
+#[derive(Debug, Clone)]
+pub struct BackgroundConfig {
+    pub star_count: u32,
+    pub layers: Vec<ParallaxLayerConfig>,
+}
+
+/// Marks a background sprite as belonging to a parallax layer, so
+/// `ParallaxSystem` knows how much to offset it by the camera position.
+/// `base_x`/`base_y` are the sprite's scattered position with no camera
+/// offset applied; `ParallaxSystem` adds the offset on top of these each
+/// frame rather than overwriting the transform outright.
+pub struct ParallaxLayer {
+    pub depth: f32,
+    pub base_x: f32,
+    pub base_y: f32,
+}
+
+impl Component for ParallaxLayer {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Scatters `config.star_count` sprites across each of `config.layers`,
+/// with random position, depth-appropriate size, at a random distance
+/// within the layer's range.
+pub fn initialise_background(
+    world: &mut World,
+    config: &BackgroundConfig,
+    sprite_sheet_handle: Handle<SpriteSheet>,
+    screen_width: f32,
+    screen_height: f32,
+) {
+    let mut rng = rand::thread_rng();
+
+    for layer in &config.layers {
+        for _ in 0..config.star_count {
+            let x = rng.gen_range(0.0..screen_width);
+            let y = rng.gen_range(0.0..screen_height);
+            let distance = rng.gen_range(layer.min_distance..layer.max_distance);
+            let size = rng.gen_range(layer.min_size..layer.max_size);
+
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(x, y, -distance);
+            transform.set_scale([size, size, 1.0].into());
+
+            world
+                .create_entity()
+                .with(SpriteRender {
+                    sprite_sheet: sprite_sheet_handle.clone(),
+                    sprite_number: 0,
+                })
+                .with(ParallaxLayer {
+                    depth: layer.depth,
+                    base_x: x,
+                    base_y: y,
+                })
+                .with(transform)
+                .build();
+        }
+    }
+}
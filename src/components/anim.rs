@@ -0,0 +1,74 @@
+use std::ops::Range;
+
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+
+/// What happens when playback reaches the end of the current section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionEdge {
+    /// Restart the section from its first frame.
+    Loop,
+    /// Stay on the last (or first, when playing backward) frame.
+    Hold,
+    /// Move on to the next section in `sections`, wrapping to the first.
+    JumpToNext,
+}
+
+/// Which way `current_frame` moves through a section as it plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackDirection {
+    Forward,
+    Backward,
+}
+
+/// A named run of frames on the sprite sheet (e.g. "walk", "idle") and the
+/// edge behavior to apply once playback reaches either end of it.
+#[derive(Debug, Clone)]
+pub struct AnimSection {
+    pub name: String,
+    pub frames: Range<usize>,
+    pub edge: TransitionEdge,
+}
+
+/// Drives an entity's `SpriteRender` through named sections of a sprite
+/// sheet over time, handling loop/hold/jump-to-next edge behavior so a
+/// dwarf can have distinct walk/idle/jump animations.
+pub struct AnimAutomaton {
+    pub sections: Vec<AnimSection>,
+    pub current_section: usize,
+    pub current_frame: usize,
+    pub direction: PlaybackDirection,
+    pub frame_duration: f32,
+    pub elapsed: f32,
+    /// When set, overrides the current section's `edge` for its next
+    /// transition only, then is cleared.
+    pub next_edge_override: Option<TransitionEdge>,
+}
+
+impl AnimAutomaton {
+    pub fn new(sections: Vec<AnimSection>, frame_duration: f32) -> Self {
+        let current_frame = sections.first().map(|s| s.frames.start).unwrap_or(0);
+        AnimAutomaton {
+            sections,
+            current_section: 0,
+            current_frame,
+            direction: PlaybackDirection::Forward,
+            frame_duration,
+            elapsed: 0.0,
+            next_edge_override: None,
+        }
+    }
+
+    /// Forces an immediate transition to the named section, restarting its
+    /// frame and elapsed timer. No-op if no section has that name.
+    pub fn jump_to(&mut self, section: &str) {
+        if let Some(index) = self.sections.iter().position(|s| s.name == section) {
+            self.current_section = index;
+            self.current_frame = self.sections[index].frames.start;
+            self.elapsed = 0.0;
+        }
+    }
+}
+
+impl Component for AnimAutomaton {
+    type Storage = DenseVecStorage<Self>;
+}
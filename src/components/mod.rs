@@ -0,0 +1,5 @@
+pub mod anim;
+pub mod physics;
+
+pub use self::anim::{AnimAutomaton, AnimSection, PlaybackDirection, TransitionEdge};
+pub use self::physics::{Dynamic, Gravity, Static};
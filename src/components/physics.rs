@@ -0,0 +1,36 @@
+use amethyst::core::math::Vector2;
+use amethyst::ecs::prelude::{Component, DenseVecStorage, NullStorage};
+
+/// Resource holding the world's downward acceleration, applied each frame
+/// to every `Dynamic` entity by `PhysicsSystem`.
+#[derive(Debug, Clone, Copy)]
+pub struct Gravity {
+    pub acceleration: f32,
+}
+
+impl Default for Gravity {
+    fn default() -> Self {
+        Gravity { acceleration: 98.0 }
+    }
+}
+
+/// A movable body with velocity and acceleration, integrated into its
+/// `Transform` by `PhysicsSystem` each frame.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Dynamic {
+    pub velocity: Vector2<f32>,
+    pub acceleration: Vector2<f32>,
+}
+
+impl Component for Dynamic {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Marks an entity as immovable level geometry (e.g. a `Tile`) that
+/// `Dynamic` entities collide against but never displace.
+#[derive(Debug, Default)]
+pub struct Static;
+
+impl Component for Static {
+    type Storage = NullStorage<Self>;
+}
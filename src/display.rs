@@ -0,0 +1,75 @@
+//! Runtime display configuration: the available fullscreen video modes,
+//! which one (if any) is active, and whether presentation waits for
+//! vsync or swaps immediately.
+
+use amethyst::window::Window;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapInterval {
+    Immediate,
+    Vsync,
+}
+
+/// One fullscreen video mode exposed by the primary monitor.
+#[derive(Debug, Clone, Copy)]
+pub struct VideoMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_rate: u16,
+}
+
+/// Resource read by `DisplaySystem` and applied to the window: which
+/// fullscreen mode (if any) is active and the current swap interval.
+#[derive(Debug, Clone)]
+pub struct DisplayConfig {
+    pub swap_interval: SwapInterval,
+    pub fullscreen_modes: Vec<VideoMode>,
+    pub current_mode: Option<usize>,
+}
+
+impl DisplayConfig {
+    /// Enumerates the primary monitor's fullscreen video modes, starting
+    /// windowed with vsync on.
+    pub fn enumerate(window: &Window) -> Self {
+        let fullscreen_modes = window
+            .primary_monitor()
+            .map(|monitor| {
+                monitor
+                    .video_modes()
+                    .map(|mode| {
+                        let size = mode.size();
+                        VideoMode {
+                            width: size.width,
+                            height: size.height,
+                            refresh_rate: mode.refresh_rate(),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        DisplayConfig {
+            swap_interval: SwapInterval::Vsync,
+            fullscreen_modes,
+            current_mode: None,
+        }
+    }
+
+    /// Flips between immediate (uncapped) and vsync present modes.
+    pub fn toggle_vsync(&mut self) {
+        self.swap_interval = match self.swap_interval {
+            SwapInterval::Immediate => SwapInterval::Vsync,
+            SwapInterval::Vsync => SwapInterval::Immediate,
+        };
+    }
+
+    /// Cycles to the next enumerated fullscreen mode, wrapping back to
+    /// windowed once every mode has been tried.
+    pub fn cycle_display_mode(&mut self) {
+        self.current_mode = match self.current_mode {
+            None if !self.fullscreen_modes.is_empty() => Some(0),
+            Some(index) if index + 1 < self.fullscreen_modes.len() => Some(index + 1),
+            _ => None,
+        };
+    }
+}
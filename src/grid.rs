@@ -0,0 +1,71 @@
+//! World <-> grid conversions for the isometric tile grid.
+//!
+//! The camera in `Pong::on_start` rotates by pi/4 on two Euler axes to
+//! fake isometry, so entities need to land on a diamond-shaped grid
+//! rather than a plain cartesian one. `ViewportGrid` holds the tile
+//! dimensions used to convert between grid coordinates (col, row) and
+//! screen-space transforms, and back again for mouse picking.
+
+use amethyst::core::math::Vector2;
+use amethyst::core::transform::Transform;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ViewportGrid {
+    pub tile_width: f32,
+    pub tile_height: f32,
+}
+
+impl ViewportGrid {
+    pub fn new(tile_width: f32, tile_height: f32) -> Self {
+        ViewportGrid {
+            tile_width,
+            tile_height,
+        }
+    }
+
+    /// Converts a (col, row) grid coordinate into a screen-space transform.
+    pub fn grid_to_world(&self, col: i32, row: i32) -> Transform {
+        let screen_x = (col - row) as f32 * (self.tile_width / 2.0);
+        let screen_y = (col + row) as f32 * (self.tile_height / 2.0);
+
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(screen_x, screen_y, 0.0);
+        transform
+    }
+
+    /// Converts a screen-space position back into the (col, row) grid
+    /// coordinate it falls on, solving the inverse of the
+    /// `[[tw/2, -tw/2], [th/2, th/2]]` projection matrix used by
+    /// `grid_to_world`.
+    pub fn world_to_grid(&self, position: Vector2<f32>) -> (i32, i32) {
+        let col = position.x / self.tile_width + position.y / self.tile_height;
+        let row = position.y / self.tile_height - position.x / self.tile_width;
+        (col.round() as i32, row.round() as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_to_world_then_world_to_grid_round_trips() {
+        let grid = ViewportGrid::new(8.0, 8.0);
+        for col in -5..5 {
+            for row in -5..5 {
+                let transform = grid.grid_to_world(col, row);
+                let translation = transform.translation();
+                let position = Vector2::new(translation.x, translation.y);
+                assert_eq!(grid.world_to_grid(position), (col, row));
+            }
+        }
+    }
+
+    #[test]
+    fn origin_maps_to_origin() {
+        let grid = ViewportGrid::new(8.0, 8.0);
+        let transform = grid.grid_to_world(0, 0);
+        let translation = transform.translation();
+        assert_eq!((translation.x, translation.y), (0.0, 0.0));
+    }
+}
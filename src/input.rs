@@ -0,0 +1,132 @@
+//! Configurable action -> input bindings, loaded from a RON file such as
+//! `resources/config/input.ron`:
+//!
+//! ```ron
+//! (
+//!     bindings: {
+//!         "Jump": [ScanCode(57), Controller(0, A)],
+//!         "LeftUp": [ScanCode(17)],
+//!     },
+//! )
+//! ```
+//!
+//! Each action lists every alternative binding that should trigger it; the
+//! action fires if *any* of them is currently pressed, so keyboard and
+//! controller both work without the caller needing to know which one the
+//! player is using.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use amethyst::input::{Button, GamepadButtonType, InputHandler, StringBindings};
+use serde::{Deserialize, Serialize};
+
+/// A controller face/shoulder button, named the way players think of it
+/// rather than by the underlying gamepad-button type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ControllerButton {
+    A,
+    B,
+    X,
+    Y,
+    LeftBumper,
+    RightBumper,
+    Start,
+    Select,
+}
+
+impl From<ControllerButton> for GamepadButtonType {
+    fn from(button: ControllerButton) -> Self {
+        match button {
+            ControllerButton::A => GamepadButtonType::South,
+            ControllerButton::B => GamepadButtonType::East,
+            ControllerButton::X => GamepadButtonType::West,
+            ControllerButton::Y => GamepadButtonType::North,
+            ControllerButton::LeftBumper => GamepadButtonType::LeftTrigger,
+            ControllerButton::RightBumper => GamepadButtonType::RightTrigger,
+            ControllerButton::Start => GamepadButtonType::Start,
+            ControllerButton::Select => GamepadButtonType::Select,
+        }
+    }
+}
+
+/// One alternative way of triggering an action.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum InputBinding {
+    ScanCode(u32),
+    Controller(u32, ControllerButton),
+}
+
+/// Action -> alternative-bindings map, loaded once at startup and read by
+/// every system that cares about player input.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InputConfig {
+    pub bindings: HashMap<String, Vec<InputBinding>>,
+}
+
+impl Default for InputConfig {
+    /// A playable fallback binding set, used whenever the RON config is
+    /// missing or malformed, so the game is still controllable without it.
+    fn default() -> Self {
+        let bindings = [
+            ("LeftUp", vec![InputBinding::ScanCode(17)]),   // W
+            ("LeftDown", vec![InputBinding::ScanCode(31)]), // S
+            ("RightUp", vec![InputBinding::ScanCode(103)]), // Up arrow
+            ("RightDown", vec![InputBinding::ScanCode(108)]), // Down arrow
+            (
+                "Jump",
+                vec![
+                    InputBinding::ScanCode(57), // Space
+                    InputBinding::Controller(0, ControllerButton::A),
+                ],
+            ),
+        ]
+        .into_iter()
+        .map(|(action, bindings)| (action.to_string(), bindings))
+        .collect();
+
+        InputConfig { bindings }
+    }
+}
+
+impl InputConfig {
+    /// Loads an action -> bindings map from a RON file, falling back to
+    /// `InputConfig::default()` and logging a warning if the file is
+    /// missing or malformed, the same way `level::initialise_level`
+    /// degrades on a missing level image instead of crashing the game.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                eprintln!("Failed to open input config '{:?}': {}, using defaults", path, err);
+                return InputConfig::default();
+            }
+        };
+
+        match ron::de::from_reader(file) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("Failed to parse input config '{:?}': {}, using defaults", path, err);
+                InputConfig::default()
+            }
+        }
+    }
+
+    /// Returns whether `action` is currently triggered by any of its
+    /// bound keys or controller buttons. Unknown actions are never down.
+    pub fn action_is_down(&self, action: &str, input: &InputHandler<StringBindings>) -> bool {
+        self.bindings
+            .get(action)
+            .map(|bindings| {
+                bindings.iter().any(|binding| match *binding {
+                    InputBinding::ScanCode(code) => input.scan_code_is_down(code),
+                    InputBinding::Controller(id, button) => {
+                        input.button_is_down(Button::Gamepad(id, button.into()))
+                    }
+                })
+            })
+            .unwrap_or(false)
+    }
+}
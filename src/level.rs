@@ -0,0 +1,130 @@
+//! Data-driven level loading.
+//!
+//! Levels are authored as PNG images under `resources/levels/`. Each
+//! opaque pixel maps to a tile kind via [`tile_kind_for_color`] and is
+//! spawned as a `Tile` entity positioned on a grid stepped by
+//! [`TILE_SIZE`]. Fully transparent pixels are skipped, so a level image
+//! only needs to paint the tiles that actually exist.
+
+use amethyst::{
+    assets::AssetStorage,
+    core::transform::Transform,
+    ecs::prelude::{Component, DenseVecStorage},
+    prelude::*,
+    renderer::{Handle, SpriteRender, SpriteSheet},
+};
+use image::GenericImageView;
+
+use crate::components::physics::Static;
+use crate::grid::ViewportGrid;
+
+/// Size, in world units, of one level tile along either axis.
+pub const TILE_SIZE: f32 = 8.0;
+
+/// The kind of tile a level pixel can decode to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileKind {
+    Ground,
+    Spawn,
+    Hazard,
+}
+
+pub struct Tile {
+    pub kind: TileKind,
+}
+
+impl Component for Tile {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Maps an opaque RGB pixel value to the tile it represents. Colors not
+/// present in this table are treated as empty space, same as fully
+/// transparent pixels.
+fn tile_kind_for_color(rgb: [u8; 3]) -> Option<TileKind> {
+    match rgb {
+        [0x66, 0x66, 0x66] => Some(TileKind::Ground),
+        [0x00, 0xff, 0x00] => Some(TileKind::Spawn),
+        [0xff, 0x00, 0x00] => Some(TileKind::Hazard),
+        _ => None,
+    }
+}
+
+/// Maps a tile kind to its sprite sheet index. Ground/Spawn/Hazard are
+/// meant to follow paddle(0)/ball(1) as sprites 2/3/4; until the sheet
+/// actually has them, the index is clamped to the sheet's last valid
+/// sprite so a tile can never be rendered out of bounds.
+fn tile_sprite_number(kind: TileKind, sprite_count: usize) -> usize {
+    let index = match kind {
+        TileKind::Ground => 2,
+        TileKind::Spawn => 3,
+        TileKind::Hazard => 4,
+    };
+    index.min(sprite_count.saturating_sub(1))
+}
+
+/// Loads `resources/levels/level{level_number}.png` and spawns one `Tile`
+/// entity per non-transparent, recognised pixel, placed on the shared
+/// `ViewportGrid` so tile geometry and `ViewportGridSystem`'s mouse
+/// picking agree on where each tile actually is.
+pub fn initialise_level(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>, level_number: u32) {
+    let path = format!("resources/levels/level{}.png", level_number);
+    let image = match image::open(&path) {
+        Ok(image) => image,
+        Err(err) => {
+            eprintln!("Failed to load level '{}': {}", path, err);
+            return;
+        }
+    };
+
+    let grid = *world.read_resource::<ViewportGrid>();
+    let sprite_count = {
+        let sprite_sheet_store = world.read_resource::<AssetStorage<SpriteSheet>>();
+        sprite_sheet_store
+            .get(&sprite_sheet_handle)
+            .map(|sheet| sheet.sprites.len())
+            .unwrap_or(1)
+    };
+
+    for (col, row, pixel) in image.pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a == 0 {
+            continue;
+        }
+        let kind = match tile_kind_for_color([r, g, b]) {
+            Some(kind) => kind,
+            None => continue,
+        };
+
+        let transform = grid.grid_to_world(col as i32, row as i32);
+
+        let sprite_render = SpriteRender {
+            sprite_sheet: sprite_sheet_handle.clone(),
+            sprite_number: tile_sprite_number(kind, sprite_count),
+        };
+
+        world
+            .create_entity()
+            .with(sprite_render)
+            .with(Tile { kind })
+            .with(Static)
+            .with(transform)
+            .build();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_to_the_sheet_s_last_sprite_when_out_of_bounds() {
+        assert_eq!(tile_sprite_number(TileKind::Hazard, 2), 1);
+    }
+
+    #[test]
+    fn uses_its_own_index_when_in_bounds() {
+        assert_eq!(tile_sprite_number(TileKind::Ground, 5), 2);
+        assert_eq!(tile_sprite_number(TileKind::Spawn, 5), 3);
+        assert_eq!(tile_sprite_number(TileKind::Hazard, 5), 4);
+    }
+}
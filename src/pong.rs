@@ -12,9 +12,16 @@ use amethyst::{
         types::DefaultBackend,
         ImageFormat, RenderingBundle, SpriteRender, SpriteSheet, SpriteSheetFormat, Texture,
     },
-    window::ScreenDimensions,
+    window::{ScreenDimensions, Window},
 };
 
+use crate::background::{initialise_background, BackgroundConfig, ParallaxLayerConfig};
+use crate::components::physics::{Dynamic, Gravity};
+use crate::display::DisplayConfig;
+use crate::grid::ViewportGrid;
+use crate::input::InputConfig;
+use crate::level::{initialise_level, TILE_SIZE};
+
 pub const ARENA_HEIGHT: f32 = 100.0;
 pub const ARENA_WIDTH: f32 = 100.0;
 
@@ -53,7 +60,53 @@ impl SimpleState for Pong {
         // `spritesheet` is the layout of the sprites on the image;
         // `texture` is the pixel data.
         self.sprite_sheet_handle.replace(load_sprite_sheet(world));
+
+        // Shared by paddle placement, the level tile loader, and mouse
+        // picking (`ViewportGridSystem`), so they all agree on where a
+        // given (col, row) actually sits on screen.
+        world.insert(ViewportGrid::new(TILE_SIZE, TILE_SIZE));
+        world.insert(InputConfig::load("resources/config/input.ron"));
+        world.insert(Gravity::default());
+        let display_config = {
+            let window = world.read_resource::<Window>();
+            DisplayConfig::enumerate(&window)
+        };
+        world.insert(display_config);
+
         initialise_paddles(world, self.sprite_sheet_handle.clone().unwrap());
+        initialise_level(world, self.sprite_sheet_handle.clone().unwrap(), 1);
+
+        let background_config = BackgroundConfig {
+            star_count: 80,
+            layers: vec![
+                ParallaxLayerConfig {
+                    depth: 0.1,
+                    min_distance: 50.0,
+                    max_distance: 100.0,
+                    min_size: 0.5,
+                    max_size: 1.0,
+                },
+                ParallaxLayerConfig {
+                    depth: 0.3,
+                    min_distance: 20.0,
+                    max_distance: 50.0,
+                    min_size: 1.0,
+                    max_size: 2.0,
+                },
+            ],
+        };
+        let (screen_w, screen_h) = {
+            let dim = world.read_resource::<ScreenDimensions>();
+            (dim.width(), dim.height())
+        };
+        initialise_background(
+            world,
+            &background_config,
+            self.sprite_sheet_handle.clone().unwrap(),
+            screen_w,
+            screen_h,
+        );
+        world.insert(background_config);
         // initialise_camera(world);
         world.insert(DebugLines::new());
         // Configure width of lines. Optional step
@@ -161,19 +214,21 @@ fn initialise_paddles(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet
         sprite_number: 0, // paddle is the first sprite in the sprite_sheet
     };
 
-    let mut left_transform = Transform::default();
-    let mut right_transform = Transform::default();
-
-    // Correctly position the paddles.
-    let y = ARENA_HEIGHT / 2.0;
-    left_transform.set_translation_xyz(PADDLE_WIDTH * 0.5, y, 0.0);
-    right_transform.set_translation_xyz(ARENA_WIDTH - PADDLE_WIDTH * 0.5, y, 0.0);
+    // Place the paddles on the isometric grid instead of a flat cartesian
+    // layout, so they sit correctly against the diamond-projected arena.
+    let grid = *world.read_resource::<ViewportGrid>();
+    let row = (ARENA_HEIGHT / TILE_SIZE / 2.0) as i32;
+    let left_transform = grid.grid_to_world(0, row);
+    let right_transform = grid.grid_to_world((ARENA_WIDTH / TILE_SIZE) as i32, row);
 
-    // Create a left plank entity.
+    // Create a left plank entity. Paddles are `Dynamic` so `PhysicsSystem`
+    // can apply gravity/jump velocity and collide them against `Static`
+    // level tiles.
     world
         .create_entity()
         .with(sprite_render.clone())
         .with(Paddle::new(Side::Left))
+        .with(Dynamic::default())
         .with(left_transform)
         .build();
 
@@ -182,6 +237,7 @@ fn initialise_paddles(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet
         .create_entity()
         .with(sprite_render)
         .with(Paddle::new(Side::Right))
+        .with(Dynamic::default())
         .with(right_transform)
         .build();
 }
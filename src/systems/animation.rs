@@ -0,0 +1,173 @@
+use amethyst::core::timing::Time;
+use amethyst::ecs::prelude::{Join, Read, System, SystemData, WriteStorage};
+use amethyst::renderer::SpriteRender;
+
+use crate::components::anim::{AnimAutomaton, PlaybackDirection, TransitionEdge};
+
+/// Advances every `AnimAutomaton` by `Time::delta_seconds` and writes the
+/// resulting frame into the entity's `SpriteRender.sprite_number`.
+pub struct AnimationSystem;
+
+impl<'s> System<'s> for AnimationSystem {
+    type SystemData = (
+        Read<'s, Time>,
+        WriteStorage<'s, AnimAutomaton>,
+        WriteStorage<'s, SpriteRender>,
+    );
+
+    fn run(&mut self, (time, mut automatons, mut sprite_renders): Self::SystemData) {
+        for (automaton, sprite_render) in (&mut automatons, &mut sprite_renders).join() {
+            automaton.elapsed += time.delta_seconds();
+
+            if automaton.elapsed >= automaton.frame_duration {
+                automaton.elapsed -= automaton.frame_duration;
+                advance_frame(automaton);
+            }
+
+            sprite_render.sprite_number = automaton.current_frame;
+        }
+    }
+}
+
+/// Steps `current_frame` one tick in `direction`, applying the current
+/// section's edge behavior (or `next_edge_override`, if set) once the end
+/// of the section is reached.
+fn advance_frame(automaton: &mut AnimAutomaton) {
+    if automaton.sections.is_empty() {
+        return;
+    }
+
+    let section = &automaton.sections[automaton.current_section];
+    let at_end = match automaton.direction {
+        PlaybackDirection::Forward => automaton.current_frame + 1 >= section.frames.end,
+        PlaybackDirection::Backward => automaton.current_frame <= section.frames.start,
+    };
+
+    if !at_end {
+        match automaton.direction {
+            PlaybackDirection::Forward => automaton.current_frame += 1,
+            PlaybackDirection::Backward => automaton.current_frame -= 1,
+        }
+        return;
+    }
+
+    let edge = automaton.next_edge_override.take().unwrap_or(section.edge);
+    match edge {
+        TransitionEdge::Loop => {
+            automaton.current_frame = match automaton.direction {
+                PlaybackDirection::Forward => section.frames.start,
+                PlaybackDirection::Backward => section.frames.end - 1,
+            };
+        }
+        TransitionEdge::Hold => {
+            // Stay on the current (last reachable) frame.
+        }
+        TransitionEdge::JumpToNext => {
+            automaton.current_section = (automaton.current_section + 1) % automaton.sections.len();
+            let next = &automaton.sections[automaton.current_section];
+            automaton.current_frame = next.frames.start;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::anim::AnimSection;
+
+    fn looping_automaton() -> AnimAutomaton {
+        AnimAutomaton::new(
+            vec![AnimSection {
+                name: "walk".to_string(),
+                frames: 0..3,
+                edge: TransitionEdge::Loop,
+            }],
+            0.1,
+        )
+    }
+
+    #[test]
+    fn no_op_when_there_are_no_sections() {
+        let mut automaton = AnimAutomaton::new(vec![], 0.1);
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_frame, 0);
+    }
+
+    #[test]
+    fn advances_one_frame_at_a_time() {
+        let mut automaton = looping_automaton();
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_frame, 1);
+    }
+
+    #[test]
+    fn loop_edge_wraps_back_to_section_start() {
+        let mut automaton = looping_automaton();
+        automaton.current_frame = 2; // last frame of 0..3
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_frame, 0);
+    }
+
+    #[test]
+    fn hold_edge_stays_on_last_frame() {
+        let mut automaton = AnimAutomaton::new(
+            vec![AnimSection {
+                name: "idle".to_string(),
+                frames: 0..3,
+                edge: TransitionEdge::Hold,
+            }],
+            0.1,
+        );
+        automaton.current_frame = 2;
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_frame, 2);
+    }
+
+    #[test]
+    fn jump_to_next_edge_moves_to_the_next_section() {
+        let mut automaton = AnimAutomaton::new(
+            vec![
+                AnimSection {
+                    name: "windup".to_string(),
+                    frames: 0..2,
+                    edge: TransitionEdge::JumpToNext,
+                },
+                AnimSection {
+                    name: "release".to_string(),
+                    frames: 2..4,
+                    edge: TransitionEdge::Loop,
+                },
+            ],
+            0.1,
+        );
+        automaton.current_frame = 1; // last frame of the first section
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_section, 1);
+        assert_eq!(automaton.current_frame, 2);
+    }
+
+    #[test]
+    fn next_edge_override_wins_once_then_clears() {
+        let mut automaton = AnimAutomaton::new(
+            vec![
+                AnimSection {
+                    name: "walk".to_string(),
+                    frames: 0..2,
+                    edge: TransitionEdge::Loop,
+                },
+                AnimSection {
+                    name: "stop".to_string(),
+                    frames: 2..4,
+                    edge: TransitionEdge::Hold,
+                },
+            ],
+            0.1,
+        );
+        automaton.current_frame = 1;
+        automaton.next_edge_override = Some(TransitionEdge::JumpToNext);
+
+        advance_frame(&mut automaton);
+        assert_eq!(automaton.current_section, 1);
+        assert!(automaton.next_edge_override.is_none());
+    }
+}
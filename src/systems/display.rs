@@ -0,0 +1,64 @@
+use amethyst::ecs::prelude::{Read, ReadExpect, System, SystemData, Write};
+use amethyst::input::{InputHandler, StringBindings};
+use amethyst::window::{DisplayConfig as WindowDisplayConfig, Window};
+use amethyst::winit::Fullscreen;
+
+use crate::display::{DisplayConfig, SwapInterval};
+use crate::input::InputConfig;
+
+/// Applies `DisplayConfig` to the window whenever `ToggleFullscreen` or
+/// `ToggleVsync` is pressed, cycling through the modes `DisplayConfig`
+/// enumerated at startup. Tracks each action's previous state so holding
+/// the key down doesn't toggle on every frame.
+#[derive(Default)]
+pub struct DisplaySystem {
+    fullscreen_was_down: bool,
+    vsync_was_down: bool,
+}
+
+impl<'s> System<'s> for DisplaySystem {
+    type SystemData = (
+        Write<'s, DisplayConfig>,
+        Write<'s, WindowDisplayConfig>,
+        ReadExpect<'s, Window>,
+        Read<'s, InputHandler<StringBindings>>,
+        ReadExpect<'s, InputConfig>,
+    );
+
+    fn run(&mut self, (mut display_config, mut window_display_config, window, input, input_config): Self::SystemData) {
+        let fullscreen_down = input_config.action_is_down("ToggleFullscreen", &input);
+        if fullscreen_down && !self.fullscreen_was_down {
+            display_config.cycle_display_mode();
+            match display_config.current_mode {
+                Some(index) => {
+                    let mode = display_config.fullscreen_modes[index];
+                    if let Some(monitor) = window.primary_monitor() {
+                        if let Some(winit_mode) = monitor
+                            .video_modes()
+                            .find(|m| {
+                                m.size().width == mode.width
+                                    && m.size().height == mode.height
+                                    && m.refresh_rate() == mode.refresh_rate
+                            })
+                        {
+                            window.set_fullscreen(Some(Fullscreen::Exclusive(winit_mode)));
+                        }
+                    }
+                }
+                None => window.set_fullscreen(None),
+            }
+        }
+        self.fullscreen_was_down = fullscreen_down;
+
+        let vsync_down = input_config.action_is_down("ToggleVsync", &input);
+        if vsync_down && !self.vsync_was_down {
+            display_config.toggle_vsync();
+            // Write straight into the real `amethyst::window::DisplayConfig`
+            // resource that `RenderToWindow` reads `vsync` from, so the
+            // swap interval we track is the one actually driving
+            // presentation, rather than a value nothing consumes.
+            window_display_config.vsync = matches!(display_config.swap_interval, SwapInterval::Vsync);
+        }
+        self.vsync_was_down = vsync_down;
+    }
+}
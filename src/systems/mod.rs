@@ -1,8 +1,12 @@
-pub use self::bounce::BounceSystem;
-pub use self::move_balls::MoveBallsSystem;
+pub use self::animation::AnimationSystem;
+pub use self::display::DisplaySystem;
 pub use self::paddle::PaddleSystem;
+pub use self::parallax::ParallaxSystem;
+pub use self::physics::PhysicsSystem;
 pub use self::viewport_grid::ViewportGridSystem;
-mod bounce;
-mod move_balls;
+mod animation;
+mod display;
 mod paddle;
+mod parallax;
+mod physics;
 mod viewport_grid;
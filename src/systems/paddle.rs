@@ -0,0 +1,66 @@
+use amethyst::core::timing::Time;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::prelude::{Join, Read, ReadExpect, ReadStorage, System, SystemData, WriteStorage};
+use amethyst::input::{InputHandler, StringBindings};
+
+use crate::components::physics::Dynamic;
+use crate::input::InputConfig;
+use crate::pong::{Paddle, Side, ARENA_HEIGHT};
+
+const PADDLE_VELOCITY: f32 = 50.0;
+const JUMP_VELOCITY: f32 = 60.0;
+
+/// Moves each paddle up or down according to its side's bound actions
+/// (`LeftUp`/`LeftDown` or `RightUp`/`RightDown`), and applies an upward
+/// impulse to its `Dynamic` velocity when `Jump` is pressed, so
+/// `PhysicsSystem` carries it the rest of the way. Bindings come from
+/// `InputConfig`, so this reacts to keyboard or controller input
+/// interchangeably.
+pub struct PaddleSystem;
+
+impl<'s> System<'s> for PaddleSystem {
+    type SystemData = (
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, Dynamic>,
+        ReadStorage<'s, Paddle>,
+        Read<'s, InputHandler<StringBindings>>,
+        ReadExpect<'s, InputConfig>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (mut transforms, mut dynamics, paddles, input, input_config, time): Self::SystemData) {
+        let jump_pressed = input_config.action_is_down("Jump", &input);
+
+        for (paddle, transform, dynamic) in (&paddles, &mut transforms, (&mut dynamics).maybe()).join() {
+            let (up_action, down_action) = match paddle.side {
+                Side::Left => ("LeftUp", "LeftDown"),
+                Side::Right => ("RightUp", "RightDown"),
+            };
+
+            let mut movement = 0.0;
+            if input_config.action_is_down(up_action, &input) {
+                movement += 1.0;
+            }
+            if input_config.action_is_down(down_action, &input) {
+                movement -= 1.0;
+            }
+
+            if movement != 0.0 {
+                let scaled_movement = movement * PADDLE_VELOCITY * time.delta_seconds();
+                let paddle_y = transform.translation().y;
+                transform.set_translation_y(
+                    (paddle_y + scaled_movement)
+                        .clamp(paddle.height / 2.0, ARENA_HEIGHT - paddle.height / 2.0),
+                );
+            }
+
+            // Only kick off a jump while roughly at rest vertically, so
+            // holding the button down doesn't keep re-triggering it mid-air.
+            if let Some(dynamic) = dynamic {
+                if jump_pressed && dynamic.velocity.y.abs() < f32::EPSILON {
+                    dynamic.velocity.y = JUMP_VELOCITY;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use amethyst::core::transform::Transform;
+use amethyst::ecs::prelude::{Join, ReadStorage, System, SystemData, WriteStorage};
+use amethyst::renderer::camera::Camera;
+
+use crate::background::ParallaxLayer;
+
+/// Offsets each background sprite's `Transform` from its scattered base
+/// position by the camera's position scaled by its layer's depth factor,
+/// so farther layers scroll slower than nearer ones as the camera moves.
+pub struct ParallaxSystem;
+
+impl<'s> System<'s> for ParallaxSystem {
+    type SystemData = (
+        ReadStorage<'s, Camera>,
+        ReadStorage<'s, ParallaxLayer>,
+        WriteStorage<'s, Transform>,
+    );
+
+    fn run(&mut self, (cameras, layers, mut transforms): Self::SystemData) {
+        let camera_translation = (&cameras, &transforms)
+            .join()
+            .next()
+            .map(|(_, transform)| *transform.translation());
+
+        let camera_translation = match camera_translation {
+            Some(translation) => translation,
+            None => return,
+        };
+
+        for (layer, transform) in (&layers, &mut transforms).join() {
+            transform.set_translation_x(layer.base_x + camera_translation.x * layer.depth);
+            transform.set_translation_y(layer.base_y + camera_translation.y * layer.depth);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amethyst::ecs::prelude::{Builder, RunNow, World, WorldExt};
+
+    #[test]
+    fn offsets_from_base_position_instead_of_replacing_it() {
+        let mut world = World::new();
+        world.register::<Camera>();
+        world.register::<ParallaxLayer>();
+        world.register::<Transform>();
+
+        let mut camera_transform = Transform::default();
+        camera_transform.set_translation_xyz(10.0, 20.0, 0.0);
+        world
+            .create_entity()
+            .with(Camera::standard_3d(100.0, 100.0))
+            .with(camera_transform)
+            .build();
+
+        let mut star_transform = Transform::default();
+        star_transform.set_translation_xyz(5.0, 7.0, -3.0);
+        let star = world
+            .create_entity()
+            .with(ParallaxLayer {
+                depth: 0.5,
+                base_x: 5.0,
+                base_y: 7.0,
+            })
+            .with(star_transform)
+            .build();
+
+        let mut system = ParallaxSystem;
+        system.run_now(&world);
+
+        let transforms = world.read_storage::<Transform>();
+        let transform = transforms.get(star).unwrap();
+        assert_eq!(transform.translation().x, 5.0 + 10.0 * 0.5);
+        assert_eq!(transform.translation().y, 7.0 + 20.0 * 0.5);
+    }
+}
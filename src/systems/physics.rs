@@ -0,0 +1,121 @@
+use amethyst::core::math::Vector2;
+use amethyst::core::timing::Time;
+use amethyst::core::transform::Transform;
+use amethyst::ecs::prelude::{Entities, Join, Read, ReadExpect, ReadStorage, System, SystemData, WriteStorage};
+
+use crate::components::physics::{Dynamic, Gravity, Static};
+use crate::level::TILE_SIZE;
+use crate::pong::ARENA_HEIGHT;
+
+/// Half-extent, in world units, of a `Dynamic` entity's collision box.
+/// Dwarves are treated as a single tile-sized square for the purposes of
+/// ground/wall collision.
+const DYNAMIC_HALF_EXTENT: f32 = TILE_SIZE / 2.0;
+const STATIC_HALF_EXTENT: f32 = TILE_SIZE / 2.0;
+
+/// Applies gravity to every `Dynamic` entity, integrates its velocity into
+/// its `Transform`, and resolves axis-separated AABB collisions against
+/// `Static` tiles so dwarves can walk on and stand against level geometry.
+pub struct PhysicsSystem;
+
+impl<'s> System<'s> for PhysicsSystem {
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, Time>,
+        ReadExpect<'s, Gravity>,
+        WriteStorage<'s, Dynamic>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, Static>,
+    );
+
+    fn run(&mut self, (entities, time, gravity, mut dynamics, mut transforms, statics): Self::SystemData) {
+        let dt = time.delta_seconds();
+
+        let static_bounds: Vec<(f32, f32)> = (&entities, &transforms, &statics)
+            .join()
+            .map(|(_, transform, _)| {
+                let translation = transform.translation();
+                (translation.x, translation.y)
+            })
+            .collect();
+
+        for (dynamic, transform) in (&mut dynamics, &mut transforms).join() {
+            dynamic.velocity.y -= gravity.acceleration * dt;
+            dynamic.velocity += dynamic.acceleration * dt;
+
+            let translation = transform.translation();
+            let mut position = Vector2::new(translation.x, translation.y);
+
+            // Integrate and resolve the X axis first, then Y, so a
+            // collision on one axis doesn't cancel movement on the other.
+            position.x += dynamic.velocity.x * dt;
+            if let Some(corrected) = resolve_axis_collision(position.x, position.y, &static_bounds, true) {
+                position.x = corrected;
+                dynamic.velocity.x = 0.0;
+            }
+
+            position.y += dynamic.velocity.y * dt;
+            if let Some(corrected) = resolve_axis_collision(position.x, position.y, &static_bounds, false) {
+                position.y = corrected;
+                dynamic.velocity.y = 0.0;
+            }
+
+            // Fall back on the arena's own floor/ceiling when there's no
+            // `Static` tile to land on (e.g. the level failed to load), so
+            // `Dynamic` entities never fall indefinitely off-screen.
+            let clamped_y = position.y.clamp(0.0, ARENA_HEIGHT);
+            if clamped_y != position.y {
+                dynamic.velocity.y = 0.0;
+                position.y = clamped_y;
+            }
+
+            transform.set_translation_x(position.x);
+            transform.set_translation_y(position.y);
+        }
+    }
+}
+
+/// Checks `(x, y)`'s `Dynamic`-sized AABB against every static tile and, on
+/// overlap, returns the corrected coordinate along the axis being resolved
+/// (`along_x` selects which one) that pushes the dynamic entity back out.
+fn resolve_axis_collision(x: f32, y: f32, static_bounds: &[(f32, f32)], along_x: bool) -> Option<f32> {
+    for &(tile_x, tile_y) in static_bounds {
+        let overlap_x = (x - tile_x).abs() < DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT;
+        let overlap_y = (y - tile_y).abs() < DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT;
+        if overlap_x && overlap_y {
+            return Some(if along_x {
+                let sign = (x - tile_x).signum();
+                tile_x + sign * (DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT)
+            } else {
+                let sign = (y - tile_y).signum();
+                tile_y + sign * (DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT)
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_collision_when_far_apart() {
+        let statics = vec![(0.0, 0.0)];
+        assert_eq!(resolve_axis_collision(100.0, 100.0, &statics, true), None);
+    }
+
+    #[test]
+    fn pushes_out_along_x_on_overlap() {
+        let statics = vec![(0.0, 0.0)];
+        let corrected = resolve_axis_collision(1.0, 0.0, &statics, true).unwrap();
+        assert_eq!(corrected, DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT);
+    }
+
+    #[test]
+    fn pushes_out_along_y_on_overlap() {
+        let statics = vec![(0.0, 0.0)];
+        let corrected = resolve_axis_collision(0.0, -1.0, &statics, false).unwrap();
+        assert_eq!(corrected, -(DYNAMIC_HALF_EXTENT + STATIC_HALF_EXTENT));
+    }
+}
@@ -0,0 +1,39 @@
+use amethyst::core::math::Vector2;
+use amethyst::ecs::prelude::{Read, ReadExpect, System, SystemData, Write};
+use amethyst::input::{InputHandler, StringBindings};
+use amethyst::window::ScreenDimensions;
+
+use crate::grid::ViewportGrid;
+
+/// The grid coordinate currently under the mouse cursor, as last computed
+/// by `ViewportGridSystem`.
+#[derive(Default)]
+pub struct HoveredTile {
+    pub col: i32,
+    pub row: i32,
+}
+
+/// Projects the mouse position onto the isometric tile grid every frame,
+/// so picking (highlighting, placement, interaction) can read
+/// `HoveredTile` instead of redoing the projection itself.
+pub struct ViewportGridSystem;
+
+impl<'s> System<'s> for ViewportGridSystem {
+    type SystemData = (
+        ReadExpect<'s, ViewportGrid>,
+        ReadExpect<'s, ScreenDimensions>,
+        Read<'s, InputHandler<StringBindings>>,
+        Write<'s, HoveredTile>,
+    );
+
+    fn run(&mut self, (grid, screen_dimensions, input, mut hovered): Self::SystemData) {
+        if let Some((mouse_x, mouse_y)) = input.mouse_position() {
+            // Mouse coordinates are measured from the top-left; our grid,
+            // like the rest of the arena, measures from the bottom-left.
+            let world = Vector2::new(mouse_x, screen_dimensions.height() - mouse_y);
+            let (col, row) = grid.world_to_grid(world);
+            hovered.col = col;
+            hovered.row = row;
+        }
+    }
+}